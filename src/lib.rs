@@ -11,12 +11,22 @@ use std::time::Duration;
 
 use foreign_types::ForeignTypeRef;
 use libc::c_int;
+use libc::c_long;
+use libc::c_uint;
 use libc::useconds_t;
 use x11::xlib::XFree;
 
 const XDO_SUCCESS: c_int = 0;
 const XDO_ERROR: c_int = 1;
 
+const SEARCH_TITLE: c_uint = 1 << 0;
+const SEARCH_CLASS: c_uint = 1 << 1;
+const SEARCH_NAME: c_uint = 1 << 2;
+const SEARCH_PID: c_uint = 1 << 3;
+const SEARCH_ONLYVISIBLE: c_uint = 1 << 4;
+const SEARCH_SCREEN: c_uint = 1 << 5;
+const SEARCH_CLASSNAME: c_uint = 1 << 6;
+
 pub struct CharcodeMapList {
     ptr: *mut ffi::Struct_charcodemap,
     len: c_int,
@@ -32,7 +42,66 @@ impl Drop for CharcodeMapList {
     }
 }
 
+impl CharcodeMapList {
+    /// Number of entries in this map
+    pub fn len(&self) -> usize {
+        self.len as usize
+    }
+
+    /// Whether this map has no entries
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Iterate over the charcode entries in this map
+    pub fn iter(&self) -> CharcodeMapIter {
+        CharcodeMapIter {
+            list: self,
+            index: 0,
+        }
+    }
+}
+
+/// A single entry in a `CharcodeMapList`
+#[derive(Debug, Clone, Copy)]
+pub struct Charcode {
+    pub code: i32,
+    pub symbol: u64,
+    pub modmask: i32,
+}
+
+/// Iterator over the entries of a `CharcodeMapList`
+pub struct CharcodeMapIter<'a> {
+    list: &'a CharcodeMapList,
+    index: usize,
+}
+
+impl<'a> Iterator for CharcodeMapIter<'a> {
+    type Item = Charcode;
+
+    fn next(&mut self) -> Option<Charcode> {
+        if self.index >= self.list.len() {
+            return None;
+        }
+
+        let entry = unsafe { &*self.list.ptr.add(self.index) };
+        self.index += 1;
+
+        Some(Charcode {
+            code: entry.code as i32,
+            symbol: entry.symbol as u64,
+            modmask: entry.modmask as i32,
+        })
+    }
+}
+
 /// Handle for the `xdo` API
+///
+/// libxdo holds a single `Display` connection per `xdo_t`, and that connection is not safe to
+/// use from more than one thread at a time. `Xdo` and `XdoRef` wrap a raw `*mut xdo_t` and are
+/// therefore neither `Send` nor `Sync` (the compiler enforces this for us, since raw pointers
+/// aren't `Send`/`Sync`). A multithreaded caller should give each thread its own connection via
+/// [`Xdo::new_on_display`] to open an independent connection rather than sharing one handle.
 foreign_type! {
     type CType = ffi::xdo_t;
     fn drop = ffi::xdo_free;
@@ -63,6 +132,93 @@ pub struct Window<'a> {
     xdo: &'a XdoRef,
 }
 
+/// Whether a `SearchQuery` must match all of its criteria, or any one of them
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Require {
+    Any,
+    All,
+}
+
+impl Default for Require {
+    fn default() -> Require {
+        Require::Any
+    }
+}
+
+/// Builder for a window search, mirroring libxdo's `xdo_search_t`
+#[derive(Default)]
+pub struct SearchQuery {
+    title: Option<String>,
+    class: Option<String>,
+    classname: Option<String>,
+    name: Option<String>,
+    pid: Option<i32>,
+    only_visible: bool,
+    screen: Option<i32>,
+    max_depth: Option<i32>,
+    require: Require,
+}
+
+impl SearchQuery {
+    pub fn new() -> SearchQuery {
+        SearchQuery::default()
+    }
+
+    /// Match the window title against this regex
+    pub fn title(mut self, title: &str) -> SearchQuery {
+        self.title = Some(title.to_owned());
+        self
+    }
+
+    /// Match the window class against this regex
+    pub fn class(mut self, class: &str) -> SearchQuery {
+        self.class = Some(class.to_owned());
+        self
+    }
+
+    /// Match the window classname against this regex
+    pub fn classname(mut self, classname: &str) -> SearchQuery {
+        self.classname = Some(classname.to_owned());
+        self
+    }
+
+    /// Match the window name against this regex
+    pub fn name(mut self, name: &str) -> SearchQuery {
+        self.name = Some(name.to_owned());
+        self
+    }
+
+    /// Only match windows owned by this PID
+    pub fn pid(mut self, pid: i32) -> SearchQuery {
+        self.pid = Some(pid);
+        self
+    }
+
+    /// Only match currently visible windows
+    pub fn only_visible(mut self, only_visible: bool) -> SearchQuery {
+        self.only_visible = only_visible;
+        self
+    }
+
+    /// Only match windows on this screen
+    pub fn screen(mut self, screen: i32) -> SearchQuery {
+        self.screen = Some(screen);
+        self
+    }
+
+    /// Limit how deep the window tree is searched
+    pub fn max_depth(mut self, max_depth: i32) -> SearchQuery {
+        self.max_depth = Some(max_depth);
+        self
+    }
+
+    /// Whether all criteria must match, or any one of them (default: any)
+    pub fn require(mut self, require: Require) -> SearchQuery {
+        self.require = require;
+        self
+    }
+}
+
 use std::fmt;
 impl<'a> fmt::Debug for Window<'a> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
@@ -121,10 +277,29 @@ fn ptr_or_error<T>(ptr: *mut T, method: &'static str) -> Result<*mut T> {
     }
 }
 
+/// Convert an optional delay to the microsecond count libxdo's `useconds_t` expects.
+///
+/// If the delay in useconds exceeds useconds_t capacity, it will be truncated.
+fn delay_to_useconds(delay: Option<Duration>) -> useconds_t {
+    delay.map(|delay| {
+        (delay.as_secs() as useconds_t).wrapping_mul(1_000_000)
+            .wrapping_add(delay.subsec_nanos() as useconds_t / 1_000)
+    }).unwrap_or(0)
+}
+
 impl Xdo {
     pub fn new() -> Result<Xdo> {
         Ok(Xdo(ptr_or_error(unsafe { ffi::xdo_new(ptr::null()) }, "xdo_new")?))
     }
+
+    /// Open a new, independent connection to the named display
+    ///
+    /// Use this to give each thread its own `xdo_t` instead of sharing a single connection,
+    /// since `Xdo`/`XdoRef` cannot be sent or shared across threads.
+    pub fn new_on_display(display_name: &str) -> Result<Xdo> {
+        let display_name = CString::new(display_name)?;
+        Ok(Xdo(ptr_or_error(unsafe { ffi::xdo_new(display_name.as_ptr()) }, "xdo_new")?))
+    }
 }
 
 impl XdoRef {
@@ -141,6 +316,116 @@ impl XdoRef {
         }
     }
 
+    /// Move the mouse to the given coordinates on the specified screen
+    pub fn move_mouse(&self, x: i32, y: i32, screen: i32) -> Result<()> {
+        let res = unsafe {
+            ffi::xdo_move_mouse(self.as_ptr(), x as c_int, y as c_int, screen as c_int)
+        };
+
+        match res {
+            XDO_SUCCESS => Ok(()),
+            XDO_ERROR => Err(Error::Failed("move_mouse")),
+            _ => unreachable!()
+        }
+    }
+
+    /// Move the mouse relative to its current position
+    pub fn move_mouse_relative(&self, dx: i32, dy: i32) -> Result<()> {
+        let res = unsafe {
+            ffi::xdo_move_mouse_relative(self.as_ptr(), dx as c_int, dy as c_int)
+        };
+
+        match res {
+            XDO_SUCCESS => Ok(()),
+            XDO_ERROR => Err(Error::Failed("move_mouse_relative")),
+            _ => unreachable!()
+        }
+    }
+
+    /// Get the current mouse location as (x, y, screen)
+    pub fn get_mouse_location(&self) -> Result<(i32, i32, i32)> {
+        let mut x: c_int = 0;
+        let mut y: c_int = 0;
+        let mut screen: c_int = 0;
+
+        let res = unsafe {
+            ffi::xdo_get_mouse_location(self.as_ptr(), &mut x, &mut y, &mut screen)
+        };
+
+        match res {
+            XDO_SUCCESS => Ok((x as i32, y as i32, screen as i32)),
+            XDO_ERROR => Err(Error::Failed("get_mouse_location")),
+            _ => unreachable!()
+        }
+    }
+
+    /// Find windows matching a `SearchQuery`
+    pub fn search(&self, query: &SearchQuery) -> Result<Vec<Window>> {
+        let title = query.title.as_ref().map(|s| CString::new(s.as_str())).transpose()?;
+        let class = query.class.as_ref().map(|s| CString::new(s.as_str())).transpose()?;
+        let classname = query.classname.as_ref().map(|s| CString::new(s.as_str())).transpose()?;
+        let name = query.name.as_ref().map(|s| CString::new(s.as_str())).transpose()?;
+
+        let mut searchmask: c_uint = 0;
+        if title.is_some() { searchmask |= SEARCH_TITLE; }
+        if class.is_some() { searchmask |= SEARCH_CLASS; }
+        if classname.is_some() { searchmask |= SEARCH_CLASSNAME; }
+        if name.is_some() { searchmask |= SEARCH_NAME; }
+        if query.pid.is_some() { searchmask |= SEARCH_PID; }
+        if query.only_visible { searchmask |= SEARCH_ONLYVISIBLE; }
+        if query.screen.is_some() { searchmask |= SEARCH_SCREEN; }
+
+        let raw = ffi::Struct_xdo_search {
+            title: title.as_ref().map(|c| c.as_ptr()).unwrap_or(ptr::null()),
+            winclass: class.as_ref().map(|c| c.as_ptr()).unwrap_or(ptr::null()),
+            winclassname: classname.as_ref().map(|c| c.as_ptr()).unwrap_or(ptr::null()),
+            winname: name.as_ref().map(|c| c.as_ptr()).unwrap_or(ptr::null()),
+            pid: query.pid.unwrap_or(0) as c_int,
+            max_depth: query.max_depth.map(|d| d as c_long).unwrap_or(-1),
+            only_visible: if query.only_visible { 1 } else { 0 },
+            screen: query.screen.unwrap_or(0) as c_int,
+            require: match query.require {
+                Require::Any => 0,
+                Require::All => 1,
+            },
+            searchmask: searchmask,
+            desktop: -1,
+            limit: 0,
+        };
+
+        let mut windowlist: *mut x11::xlib::Window = ptr::null_mut();
+        let mut nwindows: c_uint = 0;
+
+        let res = unsafe {
+            ffi::xdo_search_windows(self.as_ptr(), &raw, &mut windowlist, &mut nwindows)
+        };
+
+        match res {
+            XDO_SUCCESS => {
+                let windows = if nwindows == 0 {
+                    Vec::new()
+                } else {
+                    unsafe {
+                        ::std::slice::from_raw_parts(windowlist, nwindows as usize)
+                            .iter()
+                            .map(|&id| Window { id, xdo: self })
+                            .collect()
+                    }
+                };
+
+                if !windowlist.is_null() {
+                    unsafe {
+                        ::libc::free(windowlist as *mut _);
+                    }
+                }
+
+                Ok(windows)
+            },
+            XDO_ERROR => Err(Error::Failed("search")),
+            _ => unreachable!()
+        }
+    }
+
     pub fn get_active_modifiers(&self) -> Result<CharcodeMapList> {
         let mut list = CharcodeMapList {
             ptr: ptr::null_mut(),
@@ -157,6 +442,25 @@ impl XdoRef {
             _ => unreachable!()
         }
     }
+
+    /// Build a `CharcodeMapList` for a keysequence, e.g. `"ctrl+alt+t"`
+    pub fn charcodemap_for_keysequence(&self, keysequence: &str) -> Result<CharcodeMapList> {
+        let mut list = CharcodeMapList {
+            ptr: ptr::null_mut(),
+            len: 0,
+        };
+
+        let res = unsafe {
+            let keysequence = CString::new(keysequence)?;
+            ffi::xdo_get_charcodemap(self.as_ptr(), keysequence.as_ptr(), &mut list.ptr, &mut list.len)
+        };
+
+        match res {
+            XDO_SUCCESS => Ok(list),
+            XDO_ERROR => Err(Error::Failed("get_charcodemap")),
+            _ => unreachable!()
+        }
+    }
 }
 
 impl<'a> Window<'a> {
@@ -198,10 +502,7 @@ impl<'a> Window<'a> {
     /// The delay is convereted to microseconds internally before forwarding to libxdo. If the delay
     /// in useconds exceeds useconds_t capacity, it will be truncated.
     pub fn send_keysequence(&self, sequence: &str, delay: Option<Duration>) -> Result<()> {
-        let udelay: useconds_t = delay.map(|delay| {
-            (delay.as_secs() as useconds_t * 1_000_000)
-                + delay.subsec_nanos() as useconds_t / 1_000
-        }).unwrap_or(0);
+        let udelay = delay_to_useconds(delay);
 
         let res = unsafe {
             let sequence = CString::new(sequence)?;
@@ -215,6 +516,25 @@ impl<'a> Window<'a> {
         }
     }
 
+    /// Type the literal characters of an arbitrary UTF-8 string
+    ///
+    /// The delay is convereted to microseconds internally before forwarding to libxdo. If the delay
+    /// in useconds exceeds useconds_t capacity, it will be truncated.
+    pub fn enter_text(&self, text: &str, delay: Option<Duration>) -> Result<()> {
+        let udelay = delay_to_useconds(delay);
+
+        let res = unsafe {
+            let text = CString::new(text)?;
+            ffi::xdo_enter_text_window(self.xdo.as_ptr(), self.id, text.as_ptr(), udelay)
+        };
+
+        match res {
+            XDO_SUCCESS => Ok(()),
+            XDO_ERROR => Err(Error::Failed("enter_text")),
+            _ => unreachable!(),
+        }
+    }
+
     pub fn set_active_modifiers(&self, mods: &CharcodeMapList) -> Result<()> {
         let res = unsafe {
             ffi::xdo_set_active_modifiers(self.xdo.as_ptr(), self.id, mods.ptr, mods.len)
@@ -238,12 +558,299 @@ impl<'a> Window<'a> {
             _ => unreachable!()
         }
     }
+
+    /// Move the mouse to coordinates relative to this window
+    pub fn move_mouse_relative_to_window(&self, x: i32, y: i32) -> Result<()> {
+        let res = unsafe {
+            ffi::xdo_move_mouse_relative_to_window(self.xdo.as_ptr(), self.id, x as c_int, y as c_int)
+        };
+
+        match res {
+            XDO_SUCCESS => Ok(()),
+            XDO_ERROR => Err(Error::Failed("move_mouse_relative_to_window")),
+            _ => unreachable!()
+        }
+    }
+
+    /// Press a mouse button down. `button` is the 1-based libxdo button number.
+    pub fn mouse_down(&self, button: i32) -> Result<()> {
+        if button == 0 {
+            return Err(Error::Failed("mouse_down"));
+        }
+
+        let res = unsafe {
+            ffi::xdo_mouse_down(self.xdo.as_ptr(), self.id, button as c_int)
+        };
+
+        match res {
+            XDO_SUCCESS => Ok(()),
+            XDO_ERROR => Err(Error::Failed("mouse_down")),
+            _ => unreachable!()
+        }
+    }
+
+    /// Release a mouse button. `button` is the 1-based libxdo button number.
+    pub fn mouse_up(&self, button: i32) -> Result<()> {
+        if button == 0 {
+            return Err(Error::Failed("mouse_up"));
+        }
+
+        let res = unsafe {
+            ffi::xdo_mouse_up(self.xdo.as_ptr(), self.id, button as c_int)
+        };
+
+        match res {
+            XDO_SUCCESS => Ok(()),
+            XDO_ERROR => Err(Error::Failed("mouse_up")),
+            _ => unreachable!()
+        }
+    }
+
+    /// Click (press and release) a mouse button. `button` is the 1-based libxdo button number.
+    pub fn click(&self, button: i32) -> Result<()> {
+        if button == 0 {
+            return Err(Error::Failed("click"));
+        }
+
+        let res = unsafe {
+            ffi::xdo_click_window(self.xdo.as_ptr(), self.id, button as c_int)
+        };
+
+        match res {
+            XDO_SUCCESS => Ok(()),
+            XDO_ERROR => Err(Error::Failed("click")),
+            _ => unreachable!()
+        }
+    }
+
+    /// Activate this window, switching to its desktop and focusing/raising it
+    pub fn activate(&self) -> Result<()> {
+        let res = unsafe {
+            ffi::xdo_activate_window(self.xdo.as_ptr(), self.id)
+        };
+
+        match res {
+            XDO_SUCCESS => Ok(()),
+            XDO_ERROR => Err(Error::Failed("activate")),
+            _ => unreachable!()
+        }
+    }
+
+    /// Give this window input focus
+    pub fn focus(&self) -> Result<()> {
+        let res = unsafe {
+            ffi::xdo_focus_window(self.xdo.as_ptr(), self.id)
+        };
+
+        match res {
+            XDO_SUCCESS => Ok(()),
+            XDO_ERROR => Err(Error::Failed("focus")),
+            _ => unreachable!()
+        }
+    }
+
+    /// Raise this window above other windows
+    pub fn raise(&self) -> Result<()> {
+        let res = unsafe {
+            ffi::xdo_raise_window(self.xdo.as_ptr(), self.id)
+        };
+
+        match res {
+            XDO_SUCCESS => Ok(()),
+            XDO_ERROR => Err(Error::Failed("raise")),
+            _ => unreachable!()
+        }
+    }
+
+    /// Map this window, making it visible
+    pub fn map(&self) -> Result<()> {
+        let res = unsafe {
+            ffi::xdo_map_window(self.xdo.as_ptr(), self.id)
+        };
+
+        match res {
+            XDO_SUCCESS => Ok(()),
+            XDO_ERROR => Err(Error::Failed("map")),
+            _ => unreachable!()
+        }
+    }
+
+    /// Unmap this window, hiding it
+    pub fn unmap(&self) -> Result<()> {
+        let res = unsafe {
+            ffi::xdo_unmap_window(self.xdo.as_ptr(), self.id)
+        };
+
+        match res {
+            XDO_SUCCESS => Ok(()),
+            XDO_ERROR => Err(Error::Failed("unmap")),
+            _ => unreachable!()
+        }
+    }
+
+    /// Move this window to the given coordinates
+    pub fn move_to(&self, x: i32, y: i32) -> Result<()> {
+        let res = unsafe {
+            ffi::xdo_move_window(self.xdo.as_ptr(), self.id, x as c_int, y as c_int)
+        };
+
+        match res {
+            XDO_SUCCESS => Ok(()),
+            XDO_ERROR => Err(Error::Failed("move_to")),
+            _ => unreachable!()
+        }
+    }
+
+    /// Resize this window
+    pub fn set_size(&self, width: u32, height: u32) -> Result<()> {
+        let res = unsafe {
+            ffi::xdo_set_window_size(self.xdo.as_ptr(), self.id, width as c_int, height as c_int, 0)
+        };
+
+        match res {
+            XDO_SUCCESS => Ok(()),
+            XDO_ERROR => Err(Error::Failed("set_size")),
+            _ => unreachable!()
+        }
+    }
+
+    /// Get this window's position and size as (x, y, width, height)
+    pub fn get_geometry(&self) -> Result<(i32, i32, u32, u32)> {
+        let mut x: c_int = 0;
+        let mut y: c_int = 0;
+
+        let res = unsafe {
+            ffi::xdo_get_window_location(self.xdo.as_ptr(), self.id, &mut x, &mut y, ptr::null_mut())
+        };
+
+        match res {
+            XDO_SUCCESS => {},
+            XDO_ERROR => return Err(Error::Failed("get_geometry")),
+            _ => unreachable!()
+        }
+
+        let mut width: c_uint = 0;
+        let mut height: c_uint = 0;
+
+        let res = unsafe {
+            ffi::xdo_get_window_size(self.xdo.as_ptr(), self.id, &mut width, &mut height)
+        };
+
+        match res {
+            XDO_SUCCESS => Ok((x as i32, y as i32, width as u32, height as u32)),
+            XDO_ERROR => Err(Error::Failed("get_geometry")),
+            _ => unreachable!()
+        }
+    }
 }
 
 
 #[cfg(test)]
 mod tests {
-    use super::Xdo;
+    use super::{ffi, CharcodeMapList, Error, Require, SearchQuery, Xdo};
+
+    #[test]
+    fn charcode_map_iter() {
+        let entries = [
+            ffi::Struct_charcodemap { code: 38, symbol: 0x61, modmask: 0 },
+            ffi::Struct_charcodemap { code: 50, symbol: 0xffe1, modmask: 1 },
+        ];
+
+        let ptr = unsafe {
+            let size = entries.len() * ::std::mem::size_of::<ffi::Struct_charcodemap>();
+            let buf = ::libc::malloc(size) as *mut ffi::Struct_charcodemap;
+            ::std::ptr::copy_nonoverlapping(entries.as_ptr(), buf, entries.len());
+            buf
+        };
+
+        let list = CharcodeMapList { ptr, len: entries.len() as ::libc::c_int };
+
+        assert_eq!(list.len(), 2);
+        assert!(!list.is_empty());
+
+        let codes: Vec<i32> = list.iter().map(|c| c.code).collect();
+        assert_eq!(codes, vec![38, 50]);
+    }
+
+    #[test]
+    fn charcodemap_for_keysequence() {
+        let xdo = Xdo::new().unwrap();
+        let mods = xdo.charcodemap_for_keysequence("ctrl").unwrap();
+        let _codes: Vec<i32> = mods.iter().map(|c| c.code).collect();
+    }
+
+    #[test]
+    fn search_query_defaults() {
+        let query = SearchQuery::new();
+
+        assert!(query.title.is_none());
+        assert!(query.class.is_none());
+        assert!(query.classname.is_none());
+        assert!(query.name.is_none());
+        assert!(query.pid.is_none());
+        assert_eq!(query.only_visible, false);
+        assert!(query.screen.is_none());
+        assert!(query.max_depth.is_none());
+        assert_eq!(query.require, Require::Any);
+    }
+
+    #[test]
+    fn search_query_builder() {
+        let query = SearchQuery::new()
+            .title("term")
+            .pid(42)
+            .only_visible(true)
+            .require(Require::All);
+
+        assert_eq!(query.title.as_ref().map(|s| s.as_str()), Some("term"));
+        assert_eq!(query.pid, Some(42));
+        assert_eq!(query.only_visible, true);
+        assert_eq!(query.require, Require::All);
+    }
+
+    #[test]
+    fn search_visible_windows() {
+        let xdo = Xdo::new().unwrap();
+        let query = SearchQuery::new().only_visible(true);
+        let _windows = xdo.search(&query).unwrap();
+    }
+
+    #[test]
+    fn mouse_button_validation() {
+        let xdo = Xdo::new().unwrap();
+        let window = xdo.get_active_window().unwrap();
+
+        match window.mouse_down(0) {
+            Err(Error::Failed(_)) => {},
+            other => panic!("expected Err(Error::Failed(_)), got {:?}", other),
+        }
+
+        match window.mouse_up(0) {
+            Err(Error::Failed(_)) => {},
+            other => panic!("expected Err(Error::Failed(_)), got {:?}", other),
+        }
+
+        match window.click(0) {
+            Err(Error::Failed(_)) => {},
+            other => panic!("expected Err(Error::Failed(_)), got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn new_on_display() {
+        let display_name = ::std::env::var("DISPLAY").unwrap_or_else(|_| ":0".to_owned());
+
+        let xdo = Xdo::new_on_display(&display_name).unwrap();
+        xdo.get_active_window().unwrap();
+    }
+
+    #[test]
+    fn mouse_move_and_location() {
+        let xdo = Xdo::new().unwrap();
+        xdo.move_mouse(0, 0, 0).unwrap();
+        xdo.move_mouse_relative(1, 1).unwrap();
+        let _location = xdo.get_mouse_location().unwrap();
+    }
 
     #[test]
     fn get_window_name() {
@@ -259,6 +866,24 @@ mod tests {
         window.send_keysequence("Return", None).unwrap();
     }
 
+    #[test]
+    fn geometry_and_state() {
+        let xdo = Xdo::new().unwrap();
+        let window = xdo.get_active_window().unwrap();
+
+        window.activate().unwrap();
+        window.raise().unwrap();
+        let (x, y, _width, _height) = window.get_geometry().unwrap();
+        window.move_to(x, y).unwrap();
+    }
+
+    #[test]
+    fn enter_text() {
+        let xdo = Xdo::new().unwrap();
+        let window = xdo.get_active_window().unwrap();
+        window.enter_text("hello", None).unwrap();
+    }
+
     #[test]
     fn modifiers() {
         let xdo = Xdo::new().unwrap();